@@ -0,0 +1,83 @@
+use std::mem::MaybeUninit;
+
+use spdk_sys::{
+    spdk_nvmf_poll_group_stat,
+
+    spdk_nvmf_poll_group_get_first,
+    spdk_nvmf_poll_group_get_next,
+    spdk_nvmf_poll_group_get_stat,
+};
+
+use super::Target;
+
+/// A snapshot of NVMe-oF I/O statistics, aggregated across all of a
+/// target's poll groups.
+///
+/// Returned by [`Target::poll_group_stats`].
+///
+/// [`Target::poll_group_stats`]: method@Target::poll_group_stats
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PollGroupStats {
+    /// Total number of admin qpairs ever assigned to a poll group.
+    pub admin_qpairs: u32,
+
+    /// Total number of I/O qpairs ever assigned to a poll group.
+    pub io_qpairs: u32,
+
+    /// Number of admin qpairs currently assigned to a poll group.
+    pub current_admin_qpairs: u32,
+
+    /// Number of I/O qpairs currently assigned to a poll group.
+    pub current_io_qpairs: u32,
+
+    /// Number of bdev I/O requests currently queued, awaiting a buffer.
+    pub pending_bdev_io: u64,
+
+    /// Total bytes read from namespaces exported by this target.
+    pub bytes_read: u64,
+
+    /// Total bytes written to namespaces exported by this target.
+    pub bytes_written: u64,
+
+    /// Total read commands completed.
+    pub read_ios_completed: u64,
+
+    /// Total write commands completed.
+    pub write_ios_completed: u64,
+}
+
+impl PollGroupStats {
+    fn accumulate(&mut self, stat: &spdk_nvmf_poll_group_stat) {
+        self.admin_qpairs += stat.admin_qpairs as u32;
+        self.io_qpairs += stat.io_qpairs as u32;
+        self.current_admin_qpairs += stat.current_admin_qpairs as u32;
+        self.current_io_qpairs += stat.current_io_qpairs as u32;
+        self.pending_bdev_io += stat.pending_bdev_io as u64;
+        self.bytes_read += stat.bytes_read;
+        self.bytes_written += stat.bytes_written;
+        self.read_ios_completed += stat.read_ios_completed;
+        self.write_ios_completed += stat.write_ios_completed;
+    }
+}
+
+/// Collects and aggregates I/O statistics across every poll group on
+/// `target`.
+pub(super) fn collect(target: &Target) -> PollGroupStats {
+    let mut stats = PollGroupStats::default();
+
+    unsafe {
+        let mut pg = spdk_nvmf_poll_group_get_first(target.as_ptr());
+
+        while !pg.is_null() {
+            let mut stat = MaybeUninit::uninit();
+
+            if spdk_nvmf_poll_group_get_stat(target.as_ptr(), pg, stat.as_mut_ptr()) == 0 {
+                stats.accumulate(&stat.assume_init());
+            }
+
+            pg = spdk_nvmf_poll_group_get_next(pg);
+        }
+    }
+
+    stats
+}
@@ -0,0 +1,292 @@
+use std::{
+    ffi::CStr,
+    ptr::NonNull,
+};
+
+use spdk_sys::{
+    Errno,
+    spdk_nvmf_host,
+    spdk_nvmf_subsystem,
+    spdk_nvmf_subsystem_type,
+
+    SPDK_NVMF_SUBTYPE_DISCOVERY,
+    SPDK_NVMF_SUBTYPE_NVME,
+    SPDK_NVMF_SUBSYSTEM_STATE_ACTIVE,
+
+    to_result,
+
+    spdk_nvmf_host_get_nqn,
+    spdk_nvmf_subsystem_add_host,
+    spdk_nvmf_subsystem_allow_any_host,
+    spdk_nvmf_subsystem_get_first,
+    spdk_nvmf_subsystem_get_first_host,
+    spdk_nvmf_subsystem_get_next,
+    spdk_nvmf_subsystem_get_next_host,
+    spdk_nvmf_subsystem_get_state,
+    spdk_nvmf_subsystem_pause,
+    spdk_nvmf_subsystem_remove_host,
+    spdk_nvmf_subsystem_resume,
+    spdk_nvmf_subsystem_start,
+    spdk_nvmf_subsystem_stop,
+};
+
+use crate::{
+    nvme::SPDK_NVME_GLOBAL_NS_TAG,
+    task::{
+        Promise,
+
+        complete_with_status,
+    },
+};
+
+/// The type of a NVMe-oF subsystem, as passed to [`Target::add_subsystem`].
+///
+/// [`Target::add_subsystem`]: method@super::Target::add_subsystem
+pub enum SubsystemType {
+    /// A Discovery Controller subsystem.
+    Discovery,
+
+    /// A NVMe subsystem exporting namespaces.
+    Nvme,
+}
+
+impl From<SubsystemType> for spdk_nvmf_subsystem_type {
+    fn from(ty: SubsystemType) -> Self {
+        match ty {
+            SubsystemType::Discovery => SPDK_NVMF_SUBTYPE_DISCOVERY,
+            SubsystemType::Nvme => SPDK_NVMF_SUBTYPE_NVME,
+        }
+    }
+}
+
+/// An error from a host-ACL mutation that runs with the subsystem paused,
+/// e.g. [`Subsystem::add_host`] or [`Subsystem::set_allow_any_host`].
+///
+/// This distinguishes a failure to apply the change itself from a failure
+/// to resume the subsystem afterward, since the latter can happen after the
+/// change has already taken effect.
+#[derive(Debug)]
+pub enum HostAclError {
+    /// Pausing the subsystem ahead of the change failed, so the host ACL
+    /// was left untouched.
+    PauseFailed(Errno),
+
+    /// The change itself failed.
+    ChangeFailed(Errno),
+
+    /// The change was applied, but resuming the subsystem afterward failed;
+    /// the subsystem is left Paused rather than Active.
+    ResumeFailed(Errno),
+}
+
+/// Represents a NVMe-oF subsystem.
+pub struct Subsystem(NonNull<spdk_nvmf_subsystem>);
+
+unsafe impl Send for Subsystem {}
+
+impl Subsystem {
+    /// Returns a subsystem from a raw `spdk_nvmf_subsystem` pointer.
+    pub fn from_ptr(ptr: *mut spdk_nvmf_subsystem) -> Self {
+        match NonNull::new(ptr) {
+            Some(ptr) => Self(ptr),
+            None => panic!("subsystem pointer must not be null"),
+        }
+    }
+
+    /// Returns a pointer to the underlying `spdk_nvmf_subsystem` structure.
+    pub fn as_ptr(&self) -> *mut spdk_nvmf_subsystem {
+        self.0.as_ptr()
+    }
+
+    /// Transitions the subsystem from the Inactive to the Active state.
+    pub async fn start(&self) -> Result<(), Errno> {
+        Promise::new(|cx| {
+            unsafe {
+                to_result!(spdk_nvmf_subsystem_start(self.as_ptr(), Some(complete_with_status), cx))
+            }
+        }).await
+    }
+
+    /// Transitions the subsystem from the Active to the Inactive state.
+    pub async fn stop(&self) -> Result<(), Errno> {
+        Promise::new(|cx| {
+            unsafe {
+                to_result!(spdk_nvmf_subsystem_stop(self.as_ptr(), Some(complete_with_status), cx))
+            }
+        }).await
+    }
+
+    /// Transitions the subsystem from the Active to the Paused state.
+    ///
+    /// `ns_tag` restricts which namespace I/O is drained before the
+    /// subsystem is considered paused; pass [`SPDK_NVME_GLOBAL_NS_TAG`] to
+    /// pause all namespaces.
+    pub async fn pause(&self, ns_tag: u32) -> Result<(), Errno> {
+        Promise::new(|cx| {
+            unsafe {
+                to_result!(spdk_nvmf_subsystem_pause(self.as_ptr(), ns_tag, Some(complete_with_status), cx))
+            }
+        }).await
+    }
+
+    /// Transitions the subsystem from the Paused to the Active state.
+    pub async fn resume(&self) -> Result<(), Errno> {
+        Promise::new(|cx| {
+            unsafe {
+                to_result!(spdk_nvmf_subsystem_resume(self.as_ptr(), Some(complete_with_status), cx))
+            }
+        }).await
+    }
+
+    /// Allows or disallows any host to connect to this subsystem, bypassing
+    /// the allowed-hosts list.
+    pub fn allow_any_host(&self, enabled: bool) {
+        unsafe {
+            spdk_nvmf_subsystem_allow_any_host(self.as_ptr(), enabled);
+        }
+    }
+
+    /// Returns whether the subsystem is currently Active.
+    fn is_active(&self) -> bool {
+        unsafe { spdk_nvmf_subsystem_get_state(self.as_ptr()) == SPDK_NVMF_SUBSYSTEM_STATE_ACTIVE }
+    }
+
+    /// Runs `f` with the subsystem guaranteed to not be Active, which is the
+    /// invariant `spdk_nvmf_subsystem_add_host`/`remove_host`/
+    /// `allow_any_host` require so the host list can't be mutated while it's
+    /// racing a discovery-log read or an in-flight connect.
+    ///
+    /// A freshly-created subsystem is already Inactive, and one that's been
+    /// explicitly paused is already Paused, so `f` runs directly in those
+    /// cases. Only an Active subsystem is paused for the duration of `f` and
+    /// resumed afterward.
+    ///
+    /// `f`'s own success or failure is never silently discarded: if `f`
+    /// succeeds but the subsequent resume fails, that's reported as
+    /// [`HostAclError::ResumeFailed`] rather than as a change failure, so a
+    /// caller can tell the ACL change did take effect even though the
+    /// subsystem was left Paused.
+    async fn with_host_list_paused<T>(&self, f: impl FnOnce() -> Result<T, Errno>) -> Result<T, HostAclError> {
+        let was_active = self.is_active();
+
+        if was_active {
+            self.pause(SPDK_NVME_GLOBAL_NS_TAG).await.map_err(HostAclError::PauseFailed)?;
+        }
+
+        let result = f();
+
+        if was_active {
+            if let Err(e) = self.resume().await {
+                return match result {
+                    Ok(_) => Err(HostAclError::ResumeFailed(e)),
+                    Err(change_err) => Err(HostAclError::ChangeFailed(change_err)),
+                };
+            }
+        }
+
+        result.map_err(HostAclError::ChangeFailed)
+    }
+
+    /// Allows or disallows any host to connect to this subsystem, mirroring
+    /// [`allow_any_host`] but guarded against racing discovery-log reads or
+    /// in-flight connects.
+    ///
+    /// [`allow_any_host`]: method@Subsystem::allow_any_host
+    pub async fn set_allow_any_host(&self, enabled: bool) -> Result<(), HostAclError> {
+        self.with_host_list_paused(|| {
+            self.allow_any_host(enabled);
+            Ok(())
+        }).await
+    }
+
+    /// Adds `host_nqn` to this subsystem's list of allowed hosts.
+    ///
+    /// See [`set_allow_any_host`] for why this guards against the subsystem
+    /// being Active while the list is mutated.
+    ///
+    /// [`set_allow_any_host`]: method@Subsystem::set_allow_any_host
+    pub async fn add_host(&self, host_nqn: &CStr) -> Result<(), HostAclError> {
+        self.with_host_list_paused(|| unsafe {
+            to_result!(spdk_nvmf_subsystem_add_host(self.as_ptr(), host_nqn.as_ptr(), std::ptr::null_mut()))
+        }).await
+    }
+
+    /// Removes `host_nqn` from this subsystem's list of allowed hosts.
+    ///
+    /// See [`set_allow_any_host`] for why this guards against the subsystem
+    /// being Active while the list is mutated.
+    ///
+    /// [`set_allow_any_host`]: method@Subsystem::set_allow_any_host
+    pub async fn remove_host(&self, host_nqn: &CStr) -> Result<(), HostAclError> {
+        self.with_host_list_paused(|| unsafe {
+            to_result!(spdk_nvmf_subsystem_remove_host(self.as_ptr(), host_nqn.as_ptr()))
+        }).await
+    }
+
+    /// Returns an iterator over the NQNs of hosts allowed to connect to this
+    /// subsystem.
+    pub fn allowed_hosts(&self) -> AllowedHosts {
+        AllowedHosts::new(self)
+    }
+}
+
+/// An iterator over the NQNs of hosts allowed to connect to a [`Subsystem`].
+pub struct AllowedHosts<'a> {
+    subsystem: &'a Subsystem,
+    host: *mut spdk_nvmf_host,
+}
+
+impl<'a> AllowedHosts<'a> {
+    fn new(subsystem: &'a Subsystem) -> Self {
+        let host = unsafe { spdk_nvmf_subsystem_get_first_host(subsystem.as_ptr()) };
+
+        Self { subsystem, host }
+    }
+}
+
+impl<'a> Iterator for AllowedHosts<'a> {
+    type Item = &'a CStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.host.is_null() {
+            None
+        } else {
+            unsafe {
+                let nqn = CStr::from_ptr(spdk_nvmf_host_get_nqn(self.host));
+
+                self.host = spdk_nvmf_subsystem_get_next_host(self.subsystem.as_ptr(), self.host);
+
+                Some(nqn)
+            }
+        }
+    }
+}
+
+/// An iterator over the subsystems on a [`Target`](super::Target).
+pub struct Subsystems(*mut spdk_nvmf_subsystem);
+
+unsafe impl Send for Subsystems {}
+
+impl Subsystems {
+    pub(super) fn new(target: &super::Target) -> Self {
+        Self(unsafe { spdk_nvmf_subsystem_get_first(target.as_ptr()) })
+    }
+}
+
+impl Iterator for Subsystems {
+    type Item = Subsystem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_null() {
+            None
+        } else {
+            unsafe {
+                let subsys = self.0;
+
+                self.0 = spdk_nvmf_subsystem_get_next(subsys);
+
+                Some(Subsystem::from_ptr(subsys))
+            }
+        }
+    }
+}
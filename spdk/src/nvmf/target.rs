@@ -10,7 +10,12 @@ use std::{
 
 use spdk_sys::{
     Errno,
+    NVMF_TGT_NAME_MAX_LENGTH,
+    SPDK_TLS_PSK_MAX_LEN,
+    spdk_nvmf_listen_opts,
+    spdk_nvmf_referral_opts,
     spdk_nvmf_tgt,
+    spdk_nvmf_target_opts,
 
     to_result,
 
@@ -19,14 +24,23 @@ use spdk_sys::{
     spdk_nvmf_listen_opts_init,
     spdk_nvmf_subsystem_create,
     spdk_nvmf_subsystem_destroy,
+    spdk_nvmf_referral_opts_init,
+    spdk_nvmf_tgt_add_referral,
     spdk_nvmf_tgt_add_transport,
+    spdk_nvmf_tgt_create,
+    spdk_nvmf_tgt_destroy,
     spdk_nvmf_tgt_get_name,
     spdk_nvmf_tgt_listen_ext,
+    spdk_nvmf_tgt_opts_init,
+    spdk_nvmf_tgt_remove_referral,
+    spdk_nvmf_tgt_stop_listen_ext,
+    spdk_nvmf_transport_get_type,
 };
 
 use crate::{
     errors::{
         EINPROGRESS,
+        EINVAL,
         ENOMEM,
     },
     nvme::{
@@ -47,6 +61,11 @@ use super::{
     Subsystem,
     Transport,
 
+    poll_group_stats::{
+        self,
+
+        PollGroupStats,
+    },
     subsystem::{
         Subsystems,
         SubsystemType,
@@ -54,12 +73,216 @@ use super::{
     transport::Transports,
 };
 
+/// Options used to create a new [`Target`] via [`Target::create`].
+pub struct TargetOpts(spdk_nvmf_target_opts);
+
+impl TargetOpts {
+    /// Returns a new set of target options populated with SPDK's defaults.
+    pub fn new() -> Self {
+        unsafe {
+            let mut opts = MaybeUninit::uninit();
+
+            spdk_nvmf_tgt_opts_init(opts.as_mut_ptr(), size_of_val(&opts));
+
+            Self(opts.assume_init())
+        }
+    }
+
+    /// Sets the name of the target.
+    ///
+    /// The name is truncated to fit within [`NVMF_TGT_NAME_MAX_LENGTH`]
+    /// bytes, including the terminating nul.
+    pub fn name(mut self, name: &CStr) -> Self {
+        let src = name.to_bytes_with_nul();
+        let max = NVMF_TGT_NAME_MAX_LENGTH as usize;
+        let len = src.len().min(max);
+
+        for (dst, src) in self.0.name.iter_mut().zip(src[..len].iter()) {
+            *dst = *src as _;
+        }
+
+        self.0.name[len.min(self.0.name.len() - 1)] = 0;
+
+        self
+    }
+
+    /// Sets the maximum number of subsystems the target may host.
+    pub fn max_subsystems(mut self, max_subsystems: u32) -> Self {
+        self.0.max_subsystems = max_subsystems;
+        self
+    }
+
+    /// Sets the discovery filter applied to this target's discovery log,
+    /// as a bitwise OR of `SPDK_NVMF_TGT_DISCOVERY_*` flags.
+    pub fn discovery_filter(mut self, discovery_filter: u32) -> Self {
+        self.0.discovery_filter = discovery_filter;
+        self
+    }
+
+    fn as_ptr(&mut self) -> *mut spdk_nvmf_target_opts {
+        &mut self.0
+    }
+}
+
+/// Options for a listener added via [`Target::listen`].
+///
+/// By default a listener is plaintext. Calling [`secure_channel`] and
+/// [`psk`] configures it to require a TLS channel authenticated with a
+/// pre-shared key instead.
+///
+/// [`secure_channel`]: method@ListenOpts::secure_channel
+/// [`psk`]: method@ListenOpts::psk
+pub struct ListenOpts(spdk_nvmf_listen_opts);
+
+impl ListenOpts {
+    /// Returns a new set of listen options populated with SPDK's defaults.
+    pub fn new() -> Self {
+        unsafe {
+            let mut opts = MaybeUninit::uninit();
+
+            spdk_nvmf_listen_opts_init(opts.as_mut_ptr(), size_of_val(&opts));
+
+            Self(opts.assume_init())
+        }
+    }
+
+    /// Requires the listener to be brought up over a secure (TLS) channel.
+    pub fn secure_channel(mut self, secure_channel: bool) -> Self {
+        self.0.secure_channel = secure_channel;
+        self
+    }
+
+    /// Sets the identity used to look up the pre-shared key on the peer.
+    ///
+    /// `identity` must be no more than `SPDK_TLS_PSK_MAX_LEN` bytes,
+    /// including the terminating nul.
+    pub fn psk_identity(mut self, identity: &CStr) -> Result<Self, Errno> {
+        let identity = identity.to_bytes_with_nul();
+
+        if identity.len() > SPDK_TLS_PSK_MAX_LEN as usize {
+            return Err(EINVAL);
+        }
+
+        for dst in self.0.psk_identity.iter_mut() {
+            *dst = 0;
+        }
+
+        for (dst, src) in self.0.psk_identity.iter_mut().zip(identity.iter()) {
+            *dst = *src as _;
+        }
+
+        Ok(self)
+    }
+
+    /// Sets the pre-shared key used to authenticate the TLS channel.
+    ///
+    /// `psk` must be no more than `SPDK_TLS_PSK_MAX_LEN` bytes. The key is
+    /// copied into this `ListenOpts`, which scrubs it on drop so the key
+    /// material doesn't linger in memory longer than necessary.
+    pub fn psk(mut self, psk: &[u8]) -> Result<Self, Errno> {
+        if psk.len() > SPDK_TLS_PSK_MAX_LEN as usize {
+            return Err(EINVAL);
+        }
+
+        for dst in self.0.psk.iter_mut() {
+            *dst = 0;
+        }
+
+        for (dst, src) in self.0.psk.iter_mut().zip(psk.iter()) {
+            *dst = *src as _;
+        }
+
+        Ok(self)
+    }
+
+    fn as_ptr(&mut self) -> *mut spdk_nvmf_listen_opts {
+        &mut self.0
+    }
+}
+
+impl Drop for ListenOpts {
+    /// Scrubs the pre-shared key from memory.
+    fn drop(&mut self) {
+        for dst in self.0.psk.iter_mut() {
+            unsafe {
+                std::ptr::write_volatile(dst, 0);
+            }
+        }
+    }
+}
+
+/// Options for a discovery referral added via [`Target::add_referral`].
+pub struct ReferralOpts(spdk_nvmf_referral_opts);
+
+impl ReferralOpts {
+    /// Returns a new set of referral options populated with SPDK's defaults.
+    pub fn new() -> Self {
+        unsafe {
+            let mut opts = MaybeUninit::uninit();
+
+            spdk_nvmf_referral_opts_init(opts.as_mut_ptr(), size_of_val(&opts));
+
+            Self(opts.assume_init())
+        }
+    }
+
+    /// Marks the referred-to discovery service as reachable only over a
+    /// secure (TLS) channel.
+    pub fn secure_channel(mut self, secure_channel: bool) -> Self {
+        self.0.secure_channel = secure_channel;
+        self
+    }
+
+    fn as_ptr(&mut self) -> *mut spdk_nvmf_referral_opts {
+        &mut self.0
+    }
+}
+
 /// Represents a NVMe-oF target.
 pub struct Target(NonNull<spdk_nvmf_tgt>);
 
 unsafe impl Send for Target {}
 
 impl Target {
+    /// Creates a new, standalone NVMe-oF target.
+    ///
+    /// Unlike [`targets`], which only discovers targets created elsewhere,
+    /// this drives `spdk_nvmf_tgt_create` to bring up a new target that this
+    /// process owns and is responsible for eventually passing to
+    /// [`destroy`].
+    ///
+    /// [`destroy`]: method@Target::destroy
+    pub fn create(mut opts: TargetOpts) -> Result<Self, Errno> {
+        let tgt = unsafe { spdk_nvmf_tgt_create(opts.as_ptr()) };
+
+        if tgt.is_null() {
+            return Err(ENOMEM);
+        }
+
+        Ok(Self::from_ptr(tgt))
+    }
+
+    /// Destroys the target, consuming it.
+    ///
+    /// Destruction is asynchronous: `spdk_nvmf_tgt_destroy` signals
+    /// completion through a done callback, so this only resolves once the
+    /// target has actually been torn down rather than merely requested to be.
+    pub async fn destroy(self) -> Result<(), Errno> {
+        let ptr = self.as_ptr();
+
+        // The target is being destroyed, so there's nothing left for our
+        // `Drop` (if any) to do with the pointer.
+        mem::forget(self);
+
+        Promise::new(|cx| {
+            unsafe {
+                spdk_nvmf_tgt_destroy(ptr, Some(complete_with_status), cx);
+            }
+
+            Ok(())
+        }).await
+    }
+
     /// Returns the name of the target.
     pub fn name(&self) -> &'static CStr {
         unsafe {
@@ -221,16 +444,111 @@ impl Target {
     }
 
     /// Begins accepting new connections on the specified transport.
-    pub fn listen(&self, transport_id: &TransportId) -> Result<(), Errno> {
-        unsafe {
-            let mut opts = MaybeUninit::uninit();
+    ///
+    /// Passing `opts` brings the listener up as configured, e.g. over a
+    /// TLS/PSK-protected channel; passing `None` brings up a plain listener
+    /// using SPDK's defaults. Adding a listener is asynchronous, so this
+    /// only resolves once the port is actually accepting connections rather
+    /// than merely requested to.
+    pub async fn listen(&self, transport_id: &TransportId, opts: Option<&mut ListenOpts>) -> Result<(), Errno> {
+        let mut default_opts;
+
+        let opts = match opts {
+            Some(opts) => opts.as_ptr(),
+            None => {
+                default_opts = ListenOpts::new();
+                default_opts.as_ptr()
+            },
+        };
 
-            spdk_nvmf_listen_opts_init(opts.as_mut_ptr(), size_of_val(&opts));
+        Promise::new(|cx| {
+            unsafe {
+                to_result!(spdk_nvmf_tgt_listen_ext(self.as_ptr(), transport_id.as_ptr(), opts, Some(complete_with_status), cx))
+            }
+        }).await
+    }
 
-            let mut opts = opts.assume_init();
+    /// Stops accepting new connections on the specified transport.
+    ///
+    /// Like [`listen`], this is asynchronous and only resolves once the
+    /// listener has actually been torn down.
+    ///
+    /// [`listen`]: method@Target::listen
+    pub async fn stop_listen(&self, transport_id: &TransportId) -> Result<(), Errno> {
+        Promise::new(|cx| {
+            unsafe {
+                to_result!(spdk_nvmf_tgt_stop_listen_ext(self.as_ptr(), transport_id.as_ptr(), Some(complete_with_status), cx))
+            }
+        }).await
+    }
 
-            to_result!(spdk_nvmf_tgt_listen_ext(self.as_ptr(), transport_id.as_ptr(), &mut opts as *mut _))
+    /// Begins accepting new connections at `listen_addr` on every transport
+    /// already added to this target, so a subsystem can be reached over
+    /// e.g. both TCP and RDMA.
+    ///
+    /// `listen_addr`'s transport type is ignored; it is overridden with each
+    /// transport's own type in turn. If any transport fails to listen, the
+    /// listeners already established by this call are torn down before the
+    /// error is returned.
+    pub async fn listen_all(&self, listen_addr: &TransportId) -> Result<(), Errno> {
+        let mut trid = unsafe { *listen_addr.as_ptr() };
+        let mut established = Vec::new();
+
+        for transport in self.transports() {
+            trid.trtype = unsafe { spdk_nvmf_transport_get_type(transport.as_ptr()) };
+
+            let transport_id = TransportId::from_ptr(&mut trid);
+
+            match self.listen(&transport_id, None).await {
+                Ok(()) => established.push(trid),
+                Err(e) => {
+                    for mut trid in established {
+                        // Best-effort rollback: an error tearing down an
+                        // already-established listener doesn't change the
+                        // fact that `listen_all` itself failed.
+                        let transport_id = TransportId::from_ptr(&mut trid);
+
+                        let _ = self.stop_listen(&transport_id).await;
+                    }
+
+                    return Err(e);
+                },
+            }
         }
+
+        Ok(())
+    }
+
+    /// Registers a discovery referral pointing initiators at another
+    /// discovery service reachable at `transport_id`.
+    ///
+    /// This lets a target's discovery log page point at a centralized
+    /// discovery service, which is useful in multi-target deployments.
+    pub fn add_referral(&mut self, transport_id: &TransportId, mut opts: ReferralOpts) -> Result<(), Errno> {
+        opts.0.trid = unsafe { *transport_id.as_ptr() };
+
+        unsafe {
+            to_result!(spdk_nvmf_tgt_add_referral(self.as_ptr(), opts.as_ptr()))
+        }
+    }
+
+    /// Removes the discovery referral previously registered at
+    /// `transport_id`.
+    pub fn remove_referral(&mut self, transport_id: &TransportId) -> Result<(), Errno> {
+        let mut opts = ReferralOpts::new();
+        opts.0.trid = unsafe { *transport_id.as_ptr() };
+
+        unsafe {
+            to_result!(spdk_nvmf_tgt_remove_referral(self.as_ptr(), opts.as_ptr()))
+        }
+    }
+
+    /// Collects I/O statistics across every poll group on this target.
+    ///
+    /// This is a read-only snapshot suitable for exporting to an operator's
+    /// own metrics pipeline.
+    pub fn poll_group_stats(&self) -> PollGroupStats {
+        poll_group_stats::collect(self)
     }
 }
 
@@ -0,0 +1,182 @@
+use std::{
+    ffi::{
+        CStr,
+        CString,
+    },
+    mem::MaybeUninit,
+    ptr::NonNull,
+};
+
+use spdk_sys::{
+    Errno,
+    spdk_nvmf_transport,
+    spdk_nvmf_transport_opts,
+
+    to_result,
+
+    spdk_nvmf_transport_create,
+    spdk_nvmf_transport_destroy,
+    spdk_nvmf_transport_get_first,
+    spdk_nvmf_transport_get_next,
+    spdk_nvmf_transport_opts_init,
+};
+
+use crate::{
+    errors::{
+        EINVAL,
+        ENOMEM,
+    },
+    task::{
+        Promise,
+
+        complete_with_status,
+    },
+};
+
+use super::Target;
+
+/// Options used to create a new [`Transport`].
+///
+/// An instance is only valid for the `trtype` it was built with (e.g. `c"TCP"`
+/// or `c"RDMA"`), since `spdk_nvmf_transport_opts_init` fills in type-specific
+/// defaults; [`Transport::tcp`]/[`Transport::rdma`] reject a `TransportOpts`
+/// built for a different transport type.
+pub struct TransportOpts {
+    trtype: CString,
+    opts: spdk_nvmf_transport_opts,
+}
+
+impl TransportOpts {
+    /// Returns a new set of transport options populated with the defaults
+    /// for `trtype` (e.g. `c"TCP"` or `c"RDMA"`).
+    pub fn new(trtype: &CStr) -> Result<Self, Errno> {
+        unsafe {
+            let mut opts = MaybeUninit::uninit();
+
+            if !spdk_nvmf_transport_opts_init(trtype.as_ptr(), opts.as_mut_ptr(), std::mem::size_of_val(&opts)) {
+                return Err(ENOMEM);
+            }
+
+            Ok(Self {
+                trtype: trtype.to_owned(),
+                opts: opts.assume_init(),
+            })
+        }
+    }
+
+    /// Sets the number of shared data buffers available to the transport.
+    pub fn num_shared_buffers(mut self, num_shared_buffers: u32) -> Self {
+        self.opts.num_shared_buffers = num_shared_buffers;
+        self
+    }
+
+    /// Sets the I/O unit size, in bytes.
+    pub fn io_unit_size(mut self, io_unit_size: u32) -> Self {
+        self.opts.io_unit_size = io_unit_size;
+        self
+    }
+
+    /// Sets the maximum queue depth of a qpair on this transport.
+    pub fn max_queue_depth(mut self, max_queue_depth: u16) -> Self {
+        self.opts.max_queue_depth = max_queue_depth;
+        self
+    }
+
+    fn as_ptr(&mut self) -> *mut spdk_nvmf_transport_opts {
+        &mut self.opts
+    }
+}
+
+/// Represents a NVMe-oF transport, such as TCP or RDMA.
+pub struct Transport(NonNull<spdk_nvmf_transport>);
+
+unsafe impl Send for Transport {}
+
+impl Transport {
+    /// Returns a transport from a raw `spdk_nvmf_transport` pointer.
+    pub fn from_ptr(ptr: *mut spdk_nvmf_transport) -> Self {
+        match NonNull::new(ptr) {
+            Some(ptr) => Self(ptr),
+            None => panic!("transport pointer must not be null"),
+        }
+    }
+
+    /// Returns a pointer to the underlying `spdk_nvmf_transport` structure.
+    pub fn as_ptr(&self) -> *mut spdk_nvmf_transport {
+        self.0.as_ptr()
+    }
+
+    /// Creates a new TCP transport.
+    pub fn tcp(opts: &mut TransportOpts) -> Result<Self, Errno> {
+        Self::create(c"TCP", opts)
+    }
+
+    /// Creates a new RDMA transport.
+    pub fn rdma(opts: &mut TransportOpts) -> Result<Self, Errno> {
+        Self::create(c"RDMA", opts)
+    }
+
+    fn create(trtype: &CStr, opts: &mut TransportOpts) -> Result<Self, Errno> {
+        if opts.trtype.as_c_str() != trtype {
+            // `opts` was built with `TransportOpts::new` for a different
+            // `trtype`, so its defaults don't apply here.
+            return Err(EINVAL);
+        }
+
+        let transport = unsafe { spdk_nvmf_transport_create(trtype.as_ptr(), opts.as_ptr()) };
+
+        if transport.is_null() {
+            return Err(ENOMEM);
+        }
+
+        Ok(Self::from_ptr(transport))
+    }
+
+    /// Destroys the transport, consuming it.
+    ///
+    /// This always destroys the underlying transport, with no check of
+    /// whether a target owns it; a transport yielded by
+    /// [`Target::transports`] is destroyed just the same.
+    pub async fn destroy(self) -> Result<(), Errno> {
+        let ptr = self.as_ptr();
+
+        std::mem::forget(self);
+
+        Promise::new(|cx| {
+            unsafe {
+                spdk_nvmf_transport_destroy(ptr, Some(complete_with_status), cx);
+            }
+
+            Ok(())
+        }).await
+    }
+}
+
+/// An iterator over the transports added to a [`Target`].
+pub struct Transports(*mut spdk_nvmf_transport);
+
+unsafe impl Send for Transports {}
+
+impl Transports {
+    pub(super) fn new(target: &Target) -> Self {
+        Self(unsafe { spdk_nvmf_transport_get_first(target.as_ptr()) })
+    }
+}
+
+impl Iterator for Transports {
+    type Item = Transport;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_null() {
+            None
+        } else {
+            unsafe {
+                let transport = self.0;
+
+                self.0 = spdk_nvmf_transport_get_next(transport);
+
+                Some(Transport::from_ptr(transport))
+            }
+        }
+    }
+}